@@ -0,0 +1,123 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=GIT_COMMIT");
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+
+    println!(
+        "cargo:rustc-env=SINK_GIT_COMMIT={}",
+        git_commit(&manifest_dir)
+    );
+    println!(
+        "cargo:rustc-env=SINK_COSMWASM_STD_VERSION={}",
+        locked_version(&manifest_dir, "cosmwasm-std")
+    );
+}
+
+/// The commit the contract is built from, with a "-dirty" suffix if tracked files have
+/// uncommitted changes. Can be set via the `GIT_COMMIT` env variable for builds that
+/// do not have access to the .git directory.
+///
+/// Only a .git at the workspace root is used. A repository further up (e.g. when this
+/// workspace is vendored into another project) does not describe this code.
+fn git_commit(manifest_dir: &Path) -> String {
+    if let Some(commit) = env::var("GIT_COMMIT")
+        .ok()
+        .map(|commit| commit.trim().to_string())
+        .filter(|commit| !commit.is_empty())
+    {
+        return commit;
+    }
+
+    // contracts/<name> -> workspace root
+    let Some(workspace_root) = manifest_dir.ancestors().nth(2) else {
+        return "unknown".to_string();
+    };
+    let git_dir = workspace_root.join(".git");
+    if !git_dir.exists() {
+        return "unknown".to_string();
+    }
+    let git = |args: &[&str]| -> Option<String> {
+        Command::new("git")
+            .args(args)
+            .current_dir(workspace_root)
+            .env("GIT_DIR", &git_dir)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .and_then(|output| String::from_utf8(output.stdout).ok())
+    };
+
+    // In worktrees and submodules .git is a file pointing to the actual git dir.
+    // HEAD and index are per worktree, refs are shared via the common dir.
+    let dirs = git(&["rev-parse", "--git-dir", "--git-common-dir"]).unwrap_or_default();
+    if let Some((worktree_dir, common_dir)) = dirs.trim().split_once('\n') {
+        let (worktree_dir, common_dir) = (
+            workspace_root.join(worktree_dir),
+            workspace_root.join(common_dir),
+        );
+        rerun_if_exists(&worktree_dir.join("HEAD"));
+        rerun_if_exists(&worktree_dir.join("index"));
+        rerun_if_exists(&common_dir.join("refs"));
+        rerun_if_exists(&common_dir.join("packed-refs"));
+    }
+    // Changes to tracked files make the build dirty. Untracked files (like generated
+    // schemas and artifacts) are ignored by `git describe --dirty`.
+    let tracked = git(&["ls-files", "-z"]).unwrap_or_default();
+    for file in tracked.split('\0').filter(|file| !file.is_empty()) {
+        rerun_if_exists(&workspace_root.join(file));
+    }
+
+    // Excluding all tags makes describe print the full commit hash
+    git(&[
+        "describe",
+        "--always",
+        "--dirty",
+        "--abbrev=40",
+        "--exclude=*",
+    ])
+    .map(|commit| commit.trim().to_string())
+    .filter(|commit| !commit.is_empty())
+    .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Missing paths would make cargo rerun the build script on every build
+fn rerun_if_exists(path: &Path) {
+    if path.exists() {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
+}
+
+/// Looks up the version of a dependency in the workspace's Cargo.lock
+fn locked_version(manifest_dir: &Path, package: &str) -> String {
+    let Some(lockfile) = find_upwards(manifest_dir, "Cargo.lock") else {
+        return "unknown".to_string();
+    };
+    println!("cargo:rerun-if-changed={}", lockfile.display());
+    let content = fs::read_to_string(lockfile).unwrap_or_default();
+    let name_line = format!("name = \"{package}\"");
+    let mut lines = content.lines();
+    while let Some(line) = lines.next() {
+        if line.trim() == name_line {
+            if let Some(version) = lines
+                .next()
+                .and_then(|l| l.trim().strip_prefix("version = "))
+            {
+                return version.trim_matches('"').to_string();
+            }
+        }
+    }
+    "unknown".to_string()
+}
+
+fn find_upwards(start: &Path, name: &str) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join(name))
+        .find(|candidate| candidate.exists())
+}
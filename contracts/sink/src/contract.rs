@@ -6,9 +6,15 @@ use cw2::set_contract_version;
 use cw_storage_plus::Bound;
 
 use crate::error::ContractError;
-use crate::msg::{AshesResponse, ExecuteMsg, InstantiateMsg, QueriedAsh, QueryMsg};
+use crate::msg::{
+    AshesResponse, BuildInfoResponse, ExecuteMsg, InstantiateMsg, QueriedAsh, QueryMsg,
+};
 use crate::state::{Ash, ASHES, ASHES_LAST_ID};
 
+/// The cargo features of this crate and whether they are enabled in this build.
+/// Add an entry here when adding a feature to Cargo.toml.
+const FEATURES: &[(&str, bool)] = &[];
+
 #[entry_point]
 pub fn instantiate(
     deps: DepsMut,
@@ -56,6 +62,7 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<QueryResponse> {
         QueryMsg::AshesDesc { start_after, limit } => {
             to_json_binary(&query_ashes(deps, start_after, limit, Order::Descending)?)?
         }
+        QueryMsg::BuildInfo {} => to_json_binary(&query_build_info())?,
     };
     Ok(response)
 }
@@ -168,6 +175,19 @@ fn query_ashes(
     Ok(AshesResponse { ashes })
 }
 
+fn query_build_info() -> BuildInfoResponse {
+    BuildInfoResponse {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        git_commit: env!("SINK_GIT_COMMIT").to_string(),
+        cosmwasm_std_version: env!("SINK_COSMWASM_STD_VERSION").to_string(),
+        features: FEATURES
+            .iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(name, _)| name.to_string())
+            .collect(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -685,4 +705,39 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn query_build_info_works() {
+        let deps = mock_dependencies();
+
+        let BuildInfoResponse {
+            version,
+            git_commit,
+            cosmwasm_std_version,
+            features,
+        } = from_json(query(deps.as_ref(), mock_env(), QueryMsg::BuildInfo {}).unwrap()).unwrap();
+        assert_eq!(version, env!("CARGO_PKG_VERSION"));
+        let has_git_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../../.git")
+            .exists();
+        match option_env!("GIT_COMMIT").map(str::trim) {
+            Some(expected) if !expected.is_empty() => assert_eq!(git_commit, expected),
+            // Built from a repository checkout (SHA-1 or SHA-256 object names)
+            _ if has_git_dir => {
+                let hash = git_commit.strip_suffix("-dirty").unwrap_or(&git_commit);
+                assert!(
+                    matches!(hash.len(), 40 | 64) && hash.chars().all(|c| c.is_ascii_hexdigit()),
+                    "unexpected git commit: {git_commit}"
+                );
+            }
+            // Built from a source export like a release tarball
+            _ => assert_eq!(git_commit, "unknown"),
+        }
+        // Cargo.lock is always written before the build script runs
+        assert!(
+            cosmwasm_std_version.starts_with("2."),
+            "unexpected cosmwasm-std version: {cosmwasm_std_version}"
+        );
+        assert_eq!(features, Vec::<String>::new());
+    }
 }
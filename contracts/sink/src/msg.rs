@@ -37,6 +37,9 @@ pub enum QueryMsg {
         /// When unset, an implementation defined default will be used.
         limit: Option<u32>,
     },
+    /// Gets information about the build of the contract running at this address
+    #[returns(BuildInfoResponse)]
+    BuildInfo {},
 }
 
 /// Like Ash but plus id
@@ -69,3 +72,16 @@ impl QueriedAsh {
 pub struct AshesResponse {
     pub ashes: Vec<QueriedAsh>,
 }
+
+#[cw_serde]
+pub struct BuildInfoResponse {
+    /// The crate version
+    pub version: String,
+    /// The git commit the contract was built from or "unknown". Has a "-dirty" suffix
+    /// if tracked files had uncommitted changes.
+    pub git_commit: String,
+    /// The version of cosmwasm-std linked into the contract or "unknown"
+    pub cosmwasm_std_version: String,
+    /// The cargo features enabled for this build
+    pub features: Vec<String>,
+}